@@ -1,10 +1,20 @@
 //! A simple MNIST classifier example using our neural network implementation
 
 use llm_from_scratch::{
-    nn::{Linear, Module, ReLU, Sequential},
+    nn::{
+        loss::{cross_entropy_loss, Reduction},
+        optim::{Optimizer, Sgd},
+        Linear, Module, ReLU, Sequential,
+    },
     Tensor,
 };
 use mnist::{Mnist, MnistBuilder};
+use ndarray::Array2;
+use rand::seq::SliceRandom;
+
+const BATCH_SIZE: usize = 64;
+const EPOCHS: usize = 5;
+const LEARNING_RATE: f32 = 0.1;
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Initialize logging
@@ -12,24 +22,96 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Load MNIST dataset
     let (trn_size, rows, cols) = (50_000, 28, 28);
+    let input_dim = rows * cols;
     let (x_train, y_train, x_test, y_test) = load_mnist(trn_size)?;
 
     // Create a simple neural network
     let model = Sequential::new()
-        .add(Linear::new(rows * cols, 128, true))
+        .add(Linear::new(input_dim, 128, true))
         .add(ReLU)
         .add(Linear::new(128, 10, true));
 
     println!("Model architecture:");
-    println!("Input: {}x{} (flattened to {})", rows, cols, rows * cols);
+    println!("Input: {}x{} (flattened to {})", rows, cols, input_dim);
     println!("  Linear(128) -> ReLU -> Linear(10)");
 
-    // TODO: Add training loop
-    println!("\nNote: Training loop not implemented yet.");
+    let mut optimizer = Sgd::new(model.parameters(), LEARNING_RATE);
+    let mut indices: Vec<usize> = (0..x_train.len()).collect();
+    let mut rng = rand::thread_rng();
+
+    println!("\nTraining for {} epochs:", EPOCHS);
+    for epoch in 1..=EPOCHS {
+        indices.shuffle(&mut rng);
+
+        let mut epoch_loss = 0.0;
+        let mut num_batches = 0;
+        for batch_indices in indices.chunks(BATCH_SIZE) {
+            let (images, labels) = batch(&x_train, &y_train, batch_indices, input_dim);
+
+            let logits = model.forward(&images);
+            let loss = cross_entropy_loss(&logits, &labels, Reduction::Mean);
+            optimizer.backward_step(&loss);
+
+            epoch_loss += loss.data().sum();
+            num_batches += 1;
+        }
+
+        let test_accuracy = accuracy(&model, &x_test, &y_test, input_dim);
+        println!(
+            "  epoch {epoch}/{EPOCHS}: train loss = {:.4}, test accuracy = {:.2}%",
+            epoch_loss / num_batches as f32,
+            test_accuracy * 100.0
+        );
+    }
 
     Ok(())
 }
 
+/// Stack the images and labels at `indices` into a `[batch, input_dim]` tensor
+/// and a matching vector of class indices.
+fn batch(
+    images: &[Vec<f32>],
+    labels: &[u8],
+    indices: &[usize],
+    input_dim: usize,
+) -> (Tensor, Vec<usize>) {
+    let mut flat = Vec::with_capacity(indices.len() * input_dim);
+    let mut batch_labels = Vec::with_capacity(indices.len());
+    for &i in indices {
+        flat.extend_from_slice(&images[i]);
+        batch_labels.push(labels[i] as usize);
+    }
+
+    let data = Array2::from_shape_vec((indices.len(), input_dim), flat)
+        .expect("batch images should fill the expected shape")
+        .into_dyn();
+    (Tensor::new(data, false), batch_labels)
+}
+
+/// Fraction of `images` the model classifies correctly (argmax over logits).
+fn accuracy(model: &Sequential, images: &[Vec<f32>], labels: &[u8], input_dim: usize) -> f32 {
+    let all_indices: Vec<usize> = (0..images.len()).collect();
+    let (input, targets) = batch(images, labels, &all_indices, input_dim);
+    let logits = model.forward(&input);
+
+    let correct = logits
+        .data()
+        .outer_iter()
+        .zip(targets.iter())
+        .filter(|(row, &target)| {
+            let predicted = row
+                .iter()
+                .enumerate()
+                .max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap())
+                .map(|(idx, _)| idx)
+                .unwrap_or(0);
+            predicted == target
+        })
+        .count();
+
+    correct as f32 / labels.len() as f32
+}
+
 fn load_mnist(
     trn_size: usize,
 ) -> Result<(Vec<Vec<f32>>, Vec<u8>, Vec<Vec<f32>>, Vec<u8>), Box<dyn std::error::Error>> {
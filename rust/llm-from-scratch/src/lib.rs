@@ -9,7 +9,7 @@ pub mod nn;
 pub mod tensor;
 
 // Re-export commonly used items
-pub use nn::{activation::ReLU, linear::Linear, sequential::Sequential, Module};
+pub use nn::{LeakyReLU, Linear, Module, ReLU, Sequential, Sigmoid, Softmax, Tanh};
 pub use tensor::Tensor;
 
 #[cfg(test)]
@@ -11,6 +11,61 @@ impl Module for ReLU {
     }
 }
 
+/// Logistic sigmoid activation function
+#[derive(Debug, Default)]
+pub struct Sigmoid;
+
+impl Module for Sigmoid {
+    fn forward(&self, input: &Tensor) -> Tensor {
+        input.sigmoid()
+    }
+}
+
+/// Hyperbolic tangent activation function
+#[derive(Debug, Default)]
+pub struct Tanh;
+
+impl Module for Tanh {
+    fn forward(&self, input: &Tensor) -> Tensor {
+        input.tanh()
+    }
+}
+
+/// Leaky ReLU activation function, parameterized by a negative slope
+#[derive(Debug)]
+pub struct LeakyReLU {
+    negative_slope: f32,
+}
+
+impl LeakyReLU {
+    /// Create a leaky ReLU with the given negative slope
+    pub fn new(negative_slope: f32) -> Self {
+        Self { negative_slope }
+    }
+}
+
+impl Default for LeakyReLU {
+    fn default() -> Self {
+        Self::new(0.01)
+    }
+}
+
+impl Module for LeakyReLU {
+    fn forward(&self, input: &Tensor) -> Tensor {
+        input.leaky_relu(self.negative_slope)
+    }
+}
+
+/// Softmax activation over the last axis
+#[derive(Debug, Default)]
+pub struct Softmax;
+
+impl Module for Softmax {
+    fn forward(&self, input: &Tensor) -> Tensor {
+        input.softmax()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -23,6 +78,34 @@ mod tests {
         let output = relu.forward(&input);
 
         let expected = array![[0.0, 0.0, 1.0]].into_dyn();
-        assert_eq!(output.data(), &expected);
+        assert_eq!(output.data(), expected);
+    }
+
+    #[test]
+    fn test_sigmoid() {
+        let sigmoid = Sigmoid;
+        let input = Tensor::new(array![[0.0]].into_dyn(), false);
+        let output = sigmoid.forward(&input);
+
+        assert!((output.data()[[0, 0]] - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_leaky_relu() {
+        let leaky_relu = LeakyReLU::new(0.1);
+        let input = Tensor::new(array![[-2.0, 2.0]].into_dyn(), false);
+        let output = leaky_relu.forward(&input);
+
+        let expected = array![[-0.2, 2.0]].into_dyn();
+        assert_eq!(output.data(), expected);
+    }
+
+    #[test]
+    fn test_softmax_sums_to_one() {
+        let softmax = Softmax;
+        let input = Tensor::new(array![[1.0, 2.0, 3.0]].into_dyn(), false);
+        let output = softmax.forward(&input);
+
+        assert!((output.data().sum() - 1.0).abs() < 1e-6);
     }
 }
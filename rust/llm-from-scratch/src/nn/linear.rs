@@ -42,6 +42,17 @@ impl Module for Linear {
         }
         params
     }
+
+    fn load_parameters(&mut self, data: &mut std::vec::IntoIter<ndarray::ArrayD<f32>>) {
+        if let Some(weights) = data.next() {
+            *self.weights.data_mut() = weights;
+        }
+        if let Some(bias) = &mut self.bias {
+            if let Some(bias_data) = data.next() {
+                *bias.data_mut() = bias_data;
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,175 @@
+//! Differentiable loss functions that plug into the autodiff graph
+
+use ndarray::{Array, ArrayD, Axis, IxDyn};
+
+use crate::Tensor;
+
+/// How a per-element loss is aggregated into a scalar
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reduction {
+    /// Average over all elements
+    Mean,
+    /// Sum over all elements
+    Sum,
+    /// No aggregation; return the per-element loss unchanged
+    None,
+}
+
+fn reduce(per_element: ArrayD<f32>, reduction: Reduction) -> (ArrayD<f32>, f32) {
+    let count = per_element.len() as f32;
+    match reduction {
+        Reduction::Mean => (
+            Array::from_elem(IxDyn(&[]), per_element.sum() / count),
+            count,
+        ),
+        Reduction::Sum => (Array::from_elem(IxDyn(&[]), per_element.sum()), 1.0),
+        Reduction::None => (per_element, 1.0),
+    }
+}
+
+/// Mean squared error between `pred` and `target`: `mean((pred - target)^2)`
+pub fn mse_loss(pred: &Tensor, target: &Tensor, reduction: Reduction) -> Tensor {
+    let diff = pred.data() - target.data();
+    let per_element = diff.mapv(|d| d * d);
+    let (result, count) = reduce(per_element, reduction);
+
+    let requires_grad = pred.requires_grad();
+    let mut out = Tensor::new(result, requires_grad);
+
+    if requires_grad {
+        let pred = pred.clone();
+        out.attach_custom_grad_fn(vec![pred.clone()], move |grad_out| {
+            // grad_out broadcasts against diff: a scalar for Mean/Sum, or
+            // one entry per row for Reduction::None.
+            pred.accumulate_grad(grad_out * &diff * (2.0 / count));
+        });
+    }
+
+    out
+}
+
+/// Numerically stable log-softmax over the last axis
+fn log_softmax(logits: &ArrayD<f32>) -> ArrayD<f32> {
+    let last = logits.ndim() - 1;
+    let max = logits
+        .map_axis(Axis(last), |row| {
+            row.iter().cloned().fold(f32::NEG_INFINITY, f32::max)
+        })
+        .insert_axis(Axis(last));
+    let shifted = logits - &max;
+    let log_sum_exp = shifted
+        .mapv(f32::exp)
+        .sum_axis(Axis(last))
+        .mapv(f32::ln)
+        .insert_axis(Axis(last));
+    shifted - &log_sum_exp
+}
+
+/// Cross-entropy loss over integer class targets, fusing a numerically
+/// stable log-softmax with negative log-likelihood.
+///
+/// `logits` has shape `[batch, num_classes]`; `targets` holds one class
+/// index per row.
+pub fn cross_entropy_loss(logits: &Tensor, targets: &[usize], reduction: Reduction) -> Tensor {
+    let log_probs = log_softmax(&logits.data());
+    let batch = targets.len();
+
+    let mut nll = Array::zeros(IxDyn(&[batch]));
+    for (i, &target) in targets.iter().enumerate() {
+        nll[i] = -log_probs[[i, target]];
+    }
+    let (result, count) = reduce(nll, reduction);
+
+    let requires_grad = logits.requires_grad();
+    let mut out = Tensor::new(result, requires_grad);
+
+    if requires_grad {
+        let logits = logits.clone();
+        let targets = targets.to_vec();
+        let softmax = log_probs.mapv(f32::exp);
+        let last = Axis(softmax.ndim() - 1);
+        out.attach_custom_grad_fn(vec![logits.clone()], move |grad_out| {
+            let mut grad_logits = softmax.clone();
+            for (i, &target) in targets.iter().enumerate() {
+                grad_logits[[i, target]] -= 1.0;
+            }
+            // grad_out is a scalar for Mean/Sum, or one entry per row for
+            // Reduction::None; broadcast it over the class axis either way.
+            let grad_out_broadcast = if grad_out.ndim() == 0 {
+                grad_out.clone()
+            } else {
+                grad_out.clone().insert_axis(last)
+            };
+            logits.accumulate_grad(&grad_logits * &(grad_out_broadcast / count));
+        });
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::array;
+
+    #[test]
+    fn test_mse_loss_backward_mean() {
+        let pred = Tensor::new(array![1.0, 2.0, 3.0].into_dyn(), true);
+        let target = Tensor::new(array![0.0, 0.0, 0.0].into_dyn(), false);
+
+        let loss = mse_loss(&pred, &target, Reduction::Mean);
+        loss.backward();
+
+        // d/dpred mean((pred - target)^2) = 2 * (pred - target) / n
+        let expected = array![2.0 / 3.0, 4.0 / 3.0, 2.0].into_dyn();
+        assert_eq!(pred.grad().unwrap(), expected);
+    }
+
+    #[test]
+    fn test_mse_loss_backward_none_is_not_scaled_by_batch_size() {
+        // With Reduction::None, grad_out seeded by `.backward()` is ones of
+        // length `batch`, not a single scalar `1.0` — summing it away (as
+        // the old implementation did) scaled the gradient by `batch`.
+        let pred = Tensor::new(array![1.0, 2.0, 3.0].into_dyn(), true);
+        let target = Tensor::new(array![0.0, 0.0, 0.0].into_dyn(), false);
+
+        let loss = mse_loss(&pred, &target, Reduction::None);
+        loss.backward();
+
+        let expected = array![2.0, 4.0, 6.0].into_dyn();
+        assert_eq!(pred.grad().unwrap(), expected);
+    }
+
+    #[test]
+    fn test_cross_entropy_loss_backward_mean() {
+        let logits = Tensor::new(array![[0.0, 0.0]].into_dyn(), true);
+        let targets = [0usize];
+
+        let loss = cross_entropy_loss(&logits, &targets, Reduction::Mean);
+        loss.backward();
+
+        // softmax([0, 0]) = [0.5, 0.5]; grad = (softmax - one_hot) / batch
+        let expected = array![[-0.5, 0.5]].into_dyn();
+        let grad = logits.grad().unwrap();
+        for (actual, expected) in grad.iter().zip(expected.iter()) {
+            assert!((actual - expected).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_cross_entropy_loss_backward_none_broadcasts_over_classes() {
+        let logits = Tensor::new(array![[0.0, 0.0], [0.0, 0.0]].into_dyn(), true);
+        let targets = [0usize, 1usize];
+
+        let loss = cross_entropy_loss(&logits, &targets, Reduction::None);
+        loss.backward();
+
+        // Each row's gradient should use its own (here, uniform) grad_out
+        // entry rather than a batch-wide scalar.
+        let grad = logits.grad().unwrap();
+        let expected = array![[-0.5, 0.5], [0.5, -0.5]].into_dyn();
+        for (actual, expected) in grad.iter().zip(expected.iter()) {
+            assert!((actual - expected).abs() < 1e-6);
+        }
+    }
+}
@@ -0,0 +1,90 @@
+//! Neural network modules and layers
+
+mod activation;
+mod linear;
+pub mod loss;
+pub mod optim;
+mod sequential;
+
+pub use activation::{LeakyReLU, ReLU, Sigmoid, Softmax, Tanh};
+pub use linear::Linear;
+pub use sequential::Sequential;
+
+/// Common trait for all neural network layers
+pub trait Module {
+    /// Forward pass through the module
+    fn forward(&self, input: &super::Tensor) -> super::Tensor;
+
+    /// Get all trainable parameters
+    fn parameters(&self) -> Vec<super::Tensor> {
+        Vec::new()
+    }
+
+    /// Overwrite this module's parameters in place, consuming from `data` in
+    /// the same order as [`Module::parameters`]. Used by [`Module::load`] to
+    /// restore weights saved by [`Module::save`].
+    fn load_parameters(&mut self, data: &mut std::vec::IntoIter<ndarray::ArrayD<f32>>) {
+        let _ = data;
+    }
+
+    /// Save this module's parameters, in [`Module::parameters`] order, to `path`
+    ///
+    /// Requires `Self: Sized` (rather than taking `path: impl AsRef<Path>`)
+    /// so that `Module` stays dyn-compatible for types like [`Sequential`]
+    /// that store their children as `Box<dyn Module>`.
+    #[cfg(feature = "serialize")]
+    fn save(&self, path: &std::path::Path) -> std::io::Result<()>
+    where
+        Self: Sized,
+    {
+        let file = std::fs::File::create(path)?;
+        serde_json::to_writer(file, &self.parameters()).map_err(std::io::Error::other)
+    }
+
+    /// Load parameters previously written by [`Module::save`], matching them
+    /// back to this module's tensors via [`Module::load_parameters`]
+    #[cfg(feature = "serialize")]
+    fn load(&mut self, path: &std::path::Path) -> std::io::Result<()>
+    where
+        Self: Sized,
+    {
+        let file = std::fs::File::open(path)?;
+        let tensors: Vec<super::Tensor> =
+            serde_json::from_reader(file).map_err(std::io::Error::other)?;
+        let mut data = tensors
+            .into_iter()
+            .map(|t| t.data())
+            .collect::<Vec<_>>()
+            .into_iter();
+        self.load_parameters(&mut data);
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "serialize"))]
+mod tests {
+    use super::*;
+    use crate::nn::{Linear, Sequential};
+
+    #[test]
+    fn test_save_load_round_trips_weights() {
+        let path =
+            std::env::temp_dir().join(format!("llm-from-scratch-test-{}.json", std::process::id()));
+
+        let model = Sequential::new()
+            .add(Linear::new(3, 2, true))
+            .add(Linear::new(2, 1, true));
+        model.save(&path).expect("save should succeed");
+
+        let mut loaded = Sequential::new()
+            .add(Linear::new(3, 2, true))
+            .add(Linear::new(2, 1, true));
+        loaded.load(&path).expect("load should succeed");
+
+        let _ = std::fs::remove_file(&path);
+
+        let saved: Vec<_> = model.parameters().iter().map(|t| t.data()).collect();
+        let restored: Vec<_> = loaded.parameters().iter().map(|t| t.data()).collect();
+        assert_eq!(saved, restored);
+    }
+}
@@ -0,0 +1,128 @@
+//! Optimizers that turn accumulated gradients into parameter updates
+
+use ndarray::ArrayD;
+
+use crate::Tensor;
+
+/// Common trait for all optimizers
+pub trait Optimizer {
+    /// Apply one optimization step using each parameter's accumulated gradient
+    fn step(&mut self);
+
+    /// Clear every parameter's accumulated gradient
+    fn zero_grad(&mut self);
+
+    /// Zero gradients, backpropagate `loss`, then take one optimization step
+    fn backward_step(&mut self, loss: &Tensor) {
+        self.zero_grad();
+        loss.backward();
+        self.step();
+    }
+}
+
+/// Stochastic gradient descent, with optional momentum and weight decay
+pub struct Sgd {
+    params: Vec<Tensor>,
+    lr: f32,
+    momentum: f32,
+    weight_decay: f32,
+    velocities: Vec<Option<ArrayD<f32>>>,
+}
+
+impl Sgd {
+    /// Create a plain SGD optimizer over `params` with the given learning rate
+    pub fn new(params: Vec<Tensor>, lr: f32) -> Self {
+        Self::with_momentum(params, lr, 0.0, 0.0)
+    }
+
+    /// Create an SGD optimizer with momentum and/or weight decay
+    pub fn with_momentum(params: Vec<Tensor>, lr: f32, momentum: f32, weight_decay: f32) -> Self {
+        let velocities = params.iter().map(|_| None).collect();
+        Self {
+            params,
+            lr,
+            momentum,
+            weight_decay,
+            velocities,
+        }
+    }
+}
+
+impl Optimizer for Sgd {
+    fn step(&mut self) {
+        for (param, velocity) in self.params.iter().zip(self.velocities.iter_mut()) {
+            let Some(grad) = param.grad() else {
+                continue;
+            };
+
+            let grad = if self.weight_decay > 0.0 {
+                &grad + &(param.data() * self.weight_decay)
+            } else {
+                grad
+            };
+
+            let update = if self.momentum > 0.0 {
+                let v = match velocity {
+                    Some(v) => {
+                        *v = &*v * self.momentum + &grad;
+                        v
+                    }
+                    None => velocity.insert(grad.clone()),
+                };
+                v.clone()
+            } else {
+                grad
+            };
+
+            *param.data_mut() -= &(update * self.lr);
+        }
+    }
+
+    fn zero_grad(&mut self) {
+        for param in &self.params {
+            param.zero_grad();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::{Linear, Module};
+    use ndarray::IxDyn;
+
+    #[test]
+    fn test_step_updates_the_live_parameter_tensor() {
+        // Sgd is built from Linear::parameters(), which hands out clones of
+        // the layer's tensors. A step() must still be visible through the
+        // Linear itself (the same tensors forward() reads), not just in the
+        // optimizer's private copies.
+        let linear = Linear::new(2, 1, false);
+        let before = linear.forward(&Tensor::ones((1, 2), false)).data();
+
+        let mut optimizer = Sgd::new(linear.parameters(), 0.1);
+        for param in &linear.parameters() {
+            param.accumulate_grad(ndarray::Array::ones(param.shape()).into_dyn());
+        }
+        optimizer.step();
+
+        let after = linear.forward(&Tensor::ones((1, 2), false)).data();
+        assert_ne!(
+            before, after,
+            "optimizer step should change the layer's own weights"
+        );
+    }
+
+    #[test]
+    fn test_step_applies_learning_rate_and_weight_decay() {
+        let param = Tensor::new(ndarray::Array::from_elem(IxDyn(&[1]), 1.0), true);
+        param.accumulate_grad(ndarray::Array::from_elem(IxDyn(&[1]), 1.0));
+
+        let mut optimizer = Sgd::with_momentum(vec![param.clone()], 0.5, 0.0, 0.1);
+        optimizer.step();
+
+        // update = lr * (grad + weight_decay * data) = 0.5 * (1.0 + 0.1 * 1.0) = 0.55
+        let expected = ndarray::Array::from_elem(IxDyn(&[1]), 1.0 - 0.55);
+        assert_eq!(param.data(), expected);
+    }
+}
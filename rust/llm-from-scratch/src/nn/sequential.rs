@@ -38,6 +38,12 @@ impl Module for Sequential {
         }
         params
     }
+
+    fn load_parameters(&mut self, data: &mut std::vec::IntoIter<ndarray::ArrayD<f32>>) {
+        for module in &mut self.modules {
+            module.load_parameters(data);
+        }
+    }
 }
 
 #[cfg(test)]
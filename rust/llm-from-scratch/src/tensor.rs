@@ -0,0 +1,656 @@
+//! A simple tensor implementation for neural networks
+
+use ndarray::{Array, ArrayD, Axis, Ix2, IxDyn};
+use std::cell::{RefCell, RefMut};
+use std::collections::HashSet;
+use std::ops::{Add, Mul};
+use std::rc::Rc;
+
+type DataCell = Rc<RefCell<ArrayD<f32>>>;
+type GradCell = Rc<RefCell<Option<ArrayD<f32>>>>;
+
+/// Errors returned by the fallible `try_*` tensor operations, in place of the
+/// panics that ndarray would otherwise raise on shape mismatches.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TensorError {
+    /// The inner dimensions of a `matmul` don't agree.
+    MatmulShapeMismatch {
+        /// Shape of the left-hand operand
+        lhs: Vec<usize>,
+        /// Shape of the right-hand operand
+        rhs: Vec<usize>,
+    },
+    /// The operands are neither equal-shaped nor broadcast-compatible.
+    IncompatibleShapes {
+        /// Shape of the left-hand operand
+        lhs: Vec<usize>,
+        /// Shape of the right-hand operand
+        rhs: Vec<usize>,
+    },
+}
+
+impl std::fmt::Display for TensorError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TensorError::MatmulShapeMismatch { lhs, rhs } => write!(
+                f,
+                "cannot matmul tensors with shapes {lhs:?} and {rhs:?}: inner dimensions must match"
+            ),
+            TensorError::IncompatibleShapes { lhs, rhs } => write!(
+                f,
+                "cannot broadcast tensors with shapes {lhs:?} and {rhs:?} together"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for TensorError {}
+
+/// Whether `a` and `b` are equal or NumPy-broadcast-compatible: aligned from
+/// the trailing axis, every pair of dimensions must be equal or one of them 1.
+fn shapes_broadcastable(a: &[usize], b: &[usize]) -> bool {
+    a.iter()
+        .rev()
+        .zip(b.iter().rev())
+        .all(|(&x, &y)| x == y || x == 1 || y == 1)
+}
+
+/// A backward closure: given an output's gradient, accumulate into its parents'.
+type BackwardFn = Box<dyn Fn(&ArrayD<f32>)>;
+
+/// The op-node that produced a tensor, recording its parents and how to
+/// propagate an output gradient back into them.
+struct GradFn {
+    parents: Vec<Tensor>,
+    backward: BackwardFn,
+}
+
+/// A multi-dimensional array that supports basic operations needed for neural networks
+///
+/// `data` and `grad` are reference-counted and shared across clones, so a
+/// clone of a `Tensor` (e.g. the ones `Module::parameters()` hands to an
+/// optimizer) refers to the very same storage as the original, and
+/// in-place updates (an optimizer step, [`Tensor::zero_grad`]) are visible
+/// everywhere that tensor is used.
+#[derive(Clone)]
+pub struct Tensor {
+    data: DataCell,
+    shape: Vec<usize>,
+    requires_grad: bool,
+    grad: GradCell,
+    grad_fn: Option<Rc<GradFn>>,
+}
+
+impl std::fmt::Debug for Tensor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Tensor")
+            .field("data", &self.data.borrow())
+            .field("requires_grad", &self.requires_grad)
+            .finish()
+    }
+}
+
+impl Tensor {
+    /// Create a new tensor from an array
+    pub fn new(data: ArrayD<f32>, requires_grad: bool) -> Self {
+        let shape = data.shape().to_vec();
+        Self {
+            data: Rc::new(RefCell::new(data)),
+            shape,
+            requires_grad,
+            grad: Rc::new(RefCell::new(None)),
+            grad_fn: None,
+        }
+    }
+
+    /// Create a tensor filled with zeros with the given shape
+    pub fn zeros<D: ndarray::Dimension>(shape: D, requires_grad: bool) -> Self {
+        Self::new(Array::zeros(shape).into_dyn(), requires_grad)
+    }
+
+    /// Create a tensor filled with ones with the given shape
+    pub fn ones<D: ndarray::Dimension>(shape: D, requires_grad: bool) -> Self {
+        Self::new(Array::ones(shape).into_dyn(), requires_grad)
+    }
+
+    /// Create a tensor with random values in [0, 1) with the given shape
+    pub fn rand<D: ndarray::Dimension>(shape: D, requires_grad: bool) -> Self {
+        use ndarray_rand::rand_distr::Uniform;
+        use ndarray_rand::RandomExt;
+
+        let dist = Uniform::new(0.0, 1.0);
+        Self::new(Array::random(shape, dist).into_dyn(), requires_grad)
+    }
+
+    /// Get the shape of the tensor
+    pub fn shape(&self) -> &[usize] {
+        &self.shape
+    }
+
+    /// Get a clone of the underlying array data
+    pub fn data(&self) -> ArrayD<f32> {
+        self.data.borrow().clone()
+    }
+
+    /// Borrow the underlying array data mutably, for in-place updates (e.g.
+    /// an optimizer step). Shared with every clone of this tensor.
+    pub fn data_mut(&self) -> RefMut<'_, ArrayD<f32>> {
+        self.data.borrow_mut()
+    }
+
+    /// Whether this tensor participates in gradient tracking
+    pub fn requires_grad(&self) -> bool {
+        self.requires_grad
+    }
+
+    /// Get the accumulated gradient, if any has been computed yet
+    pub fn grad(&self) -> Option<ArrayD<f32>> {
+        self.grad.borrow().clone()
+    }
+
+    /// Clear the accumulated gradient, e.g. before the next backward pass
+    pub fn zero_grad(&self) {
+        *self.grad.borrow_mut() = None;
+    }
+
+    /// Identity of the underlying gradient cell, used to de-duplicate tensors
+    /// that share storage (clones of the same logical tensor) when walking the graph.
+    fn id(&self) -> *const RefCell<Option<ArrayD<f32>>> {
+        Rc::as_ptr(&self.grad)
+    }
+
+    fn attach_grad_fn(&mut self, parents: Vec<Tensor>, backward: impl Fn(&ArrayD<f32>) + 'static) {
+        self.grad_fn = Some(Rc::new(GradFn {
+            parents,
+            backward: Box::new(backward),
+        }));
+    }
+
+    /// Attach a custom backward closure to this tensor, as an op outside the
+    /// `tensor` module (e.g. a loss function) would. `parents` are the
+    /// tensors that fed into producing `self`.
+    pub(crate) fn attach_custom_grad_fn(
+        &mut self,
+        parents: Vec<Tensor>,
+        backward: impl Fn(&ArrayD<f32>) + 'static,
+    ) {
+        self.attach_grad_fn(parents, backward);
+    }
+
+    /// Accumulate `contribution` into this tensor's gradient buffer.
+    pub(crate) fn accumulate_grad(&self, contribution: ArrayD<f32>) {
+        accumulate(&self.grad, contribution);
+    }
+
+    /// Run reverse-mode automatic differentiation starting from this tensor.
+    ///
+    /// Seeds this tensor's gradient with ones, walks the graph in reverse
+    /// topological order (DFS post-order over parents, de-duplicated by
+    /// shared gradient storage), and invokes each node's backward closure to
+    /// accumulate into its parents' gradients.
+    pub fn backward(&self) {
+        let mut visited = HashSet::new();
+        let mut topo = Vec::new();
+        build_topo(self, &mut visited, &mut topo);
+
+        *self.grad.borrow_mut() = Some(Array::ones(self.shape.as_slice()).into_dyn());
+
+        for t in topo.iter().rev() {
+            let Some(node) = &t.grad_fn else { continue };
+            let grad_output = t.grad.borrow().clone();
+            let Some(grad_output) = grad_output else {
+                continue;
+            };
+            (node.backward)(&grad_output);
+        }
+    }
+
+    /// Matrix multiplication (dot product) with another tensor.
+    ///
+    /// Both tensors must be 2-D. Panics if the inner dimensions don't agree;
+    /// use [`Tensor::try_matmul`] for a fallible version.
+    pub fn matmul(&self, other: &Tensor) -> Self {
+        let result = as_matrix(&self.data.borrow())
+            .dot(&as_matrix(&other.data.borrow()))
+            .into_dyn();
+        let requires_grad = self.requires_grad || other.requires_grad;
+        let mut out = Self::new(result, requires_grad);
+
+        if requires_grad {
+            let a = self.clone();
+            let b = other.clone();
+            out.attach_grad_fn(vec![self.clone(), other.clone()], move |grad_out| {
+                let grad_out = as_matrix(grad_out);
+                if a.requires_grad {
+                    let b_data = b.data.borrow();
+                    let grad_a = grad_out.dot(&as_matrix(&b_data).t()).into_dyn();
+                    accumulate(&a.grad, grad_a);
+                }
+                if b.requires_grad {
+                    let a_data = a.data.borrow();
+                    let grad_b = as_matrix(&a_data).t().dot(&grad_out).into_dyn();
+                    accumulate(&b.grad, grad_b);
+                }
+            });
+        }
+
+        out
+    }
+
+    /// Fallible [`Tensor::matmul`] that validates the inner dimensions agree
+    /// instead of letting ndarray panic.
+    pub fn try_matmul(&self, other: &Tensor) -> Result<Self, TensorError> {
+        let lhs_inner = self.shape.last().copied();
+        let rhs_inner = other.shape.first().copied();
+
+        if lhs_inner.is_none() || rhs_inner.is_none() || lhs_inner != rhs_inner {
+            return Err(TensorError::MatmulShapeMismatch {
+                lhs: self.shape.clone(),
+                rhs: other.shape.clone(),
+            });
+        }
+
+        Ok(self.matmul(other))
+    }
+
+    /// Element-wise addition, broadcasting the smaller operand like NumPy.
+    ///
+    /// Panics if the shapes aren't broadcast-compatible; use
+    /// [`Tensor::try_add`] for a fallible version.
+    pub fn add(&self, other: &Tensor) -> Self {
+        let result = &*self.data.borrow() + &*other.data.borrow();
+        let requires_grad = self.requires_grad || other.requires_grad;
+        let mut out = Self::new(result, requires_grad);
+
+        if requires_grad {
+            let a = self.clone();
+            let b = other.clone();
+            let a_shape = self.shape.clone();
+            let b_shape = other.shape.clone();
+            out.attach_grad_fn(vec![self.clone(), other.clone()], move |grad_out| {
+                if a.requires_grad {
+                    accumulate(&a.grad, reduce_grad_to_shape(grad_out, &a_shape));
+                }
+                if b.requires_grad {
+                    accumulate(&b.grad, reduce_grad_to_shape(grad_out, &b_shape));
+                }
+            });
+        }
+
+        out
+    }
+
+    /// Fallible [`Tensor::add`] that validates the shapes are equal or
+    /// broadcast-compatible instead of letting ndarray panic.
+    pub fn try_add(&self, other: &Tensor) -> Result<Self, TensorError> {
+        if !shapes_broadcastable(&self.shape, &other.shape) {
+            return Err(TensorError::IncompatibleShapes {
+                lhs: self.shape.clone(),
+                rhs: other.shape.clone(),
+            });
+        }
+
+        Ok(self.add(other))
+    }
+
+    /// Element-wise multiplication
+    pub fn mul(&self, other: &Tensor) -> Self {
+        let result = &*self.data.borrow() * &*other.data.borrow();
+        let requires_grad = self.requires_grad || other.requires_grad;
+        let mut out = Self::new(result, requires_grad);
+
+        if requires_grad {
+            let a = self.clone();
+            let b = other.clone();
+            let a_shape = self.shape.clone();
+            let b_shape = other.shape.clone();
+            out.attach_grad_fn(vec![self.clone(), other.clone()], move |grad_out| {
+                if a.requires_grad {
+                    let grad_a = grad_out * &*b.data.borrow();
+                    accumulate(&a.grad, reduce_grad_to_shape(&grad_a, &a_shape));
+                }
+                if b.requires_grad {
+                    let grad_b = grad_out * &*a.data.borrow();
+                    accumulate(&b.grad, reduce_grad_to_shape(&grad_b, &b_shape));
+                }
+            });
+        }
+
+        out
+    }
+
+    /// Apply ReLU activation function
+    pub fn relu(&self) -> Self {
+        let result = self.data.borrow().mapv(|x| if x > 0.0 { x } else { 0.0 });
+        let requires_grad = self.requires_grad;
+        let mut out = Self::new(result, requires_grad);
+
+        if requires_grad {
+            let a = self.clone();
+            out.attach_grad_fn(vec![self.clone()], move |grad_out| {
+                let mask = a.data.borrow().mapv(|x| if x > 0.0 { 1.0 } else { 0.0 });
+                accumulate(&a.grad, grad_out * &mask);
+            });
+        }
+
+        out
+    }
+
+    /// Apply the logistic sigmoid function: `1 / (1 + e^-x)`
+    pub fn sigmoid(&self) -> Self {
+        let result = self.data.borrow().mapv(|x| 1.0 / (1.0 + (-x).exp()));
+        let requires_grad = self.requires_grad;
+        let mut out = Self::new(result.clone(), requires_grad);
+
+        if requires_grad {
+            let a = self.clone();
+            let s = result;
+            out.attach_grad_fn(vec![self.clone()], move |grad_out| {
+                let grad_in = grad_out * &(&s * &s.mapv(|v| 1.0 - v));
+                accumulate(&a.grad, grad_in);
+            });
+        }
+
+        out
+    }
+
+    /// Apply the hyperbolic tangent function
+    pub fn tanh(&self) -> Self {
+        let result = self.data.borrow().mapv(f32::tanh);
+        let requires_grad = self.requires_grad;
+        let mut out = Self::new(result.clone(), requires_grad);
+
+        if requires_grad {
+            let a = self.clone();
+            let t = result;
+            out.attach_grad_fn(vec![self.clone()], move |grad_out| {
+                let grad_in = grad_out * &t.mapv(|v| 1.0 - v * v);
+                accumulate(&a.grad, grad_in);
+            });
+        }
+
+        out
+    }
+
+    /// Apply a leaky ReLU: `x` when positive, `negative_slope * x` otherwise
+    pub fn leaky_relu(&self, negative_slope: f32) -> Self {
+        let result = self
+            .data
+            .borrow()
+            .mapv(|x| if x > 0.0 { x } else { negative_slope * x });
+        let requires_grad = self.requires_grad;
+        let mut out = Self::new(result, requires_grad);
+
+        if requires_grad {
+            let a = self.clone();
+            out.attach_grad_fn(vec![self.clone()], move |grad_out| {
+                let mask = a
+                    .data
+                    .borrow()
+                    .mapv(|x| if x > 0.0 { 1.0 } else { negative_slope });
+                accumulate(&a.grad, grad_out * &mask);
+            });
+        }
+
+        out
+    }
+
+    /// Apply softmax over the last axis, using the max-subtraction trick for
+    /// numerical stability
+    pub fn softmax(&self) -> Self {
+        let last = Axis(self.shape.len() - 1);
+        let data = self.data.borrow();
+        let max = data
+            .map_axis(last, |row| {
+                row.iter().cloned().fold(f32::NEG_INFINITY, f32::max)
+            })
+            .insert_axis(last);
+        let exp = (&*data - &max).mapv(f32::exp);
+        let sum = exp.sum_axis(last).insert_axis(last);
+        let result = &exp / &sum;
+        drop(data);
+
+        let requires_grad = self.requires_grad;
+        let mut out = Self::new(result.clone(), requires_grad);
+
+        if requires_grad {
+            let a = self.clone();
+            let s = result;
+            out.attach_grad_fn(vec![self.clone()], move |grad_out| {
+                // dL/dx_i = s_i * (dL/dy_i - sum_j(dL/dy_j * s_j))
+                let dot = (grad_out * &s).sum_axis(last).insert_axis(last);
+                let grad_in = &s * &(grad_out - &dot);
+                accumulate(&a.grad, grad_in);
+            });
+        }
+
+        out
+    }
+}
+
+fn build_topo(
+    t: &Tensor,
+    visited: &mut HashSet<*const RefCell<Option<ArrayD<f32>>>>,
+    topo: &mut Vec<Tensor>,
+) {
+    if !visited.insert(t.id()) {
+        return;
+    }
+    if let Some(node) = &t.grad_fn {
+        for parent in &node.parents {
+            build_topo(parent, visited, topo);
+        }
+    }
+    topo.push(t.clone());
+}
+
+/// View an `ArrayD` as a 2-D matrix for `.dot()`, which ndarray only
+/// implements for fixed-rank arrays, never `IxDyn`. Panics if `a` isn't 2-D;
+/// [`Tensor::matmul`] is the only caller and only ever operates on matrices.
+fn as_matrix(a: &ArrayD<f32>) -> ndarray::ArrayView2<'_, f32> {
+    a.view()
+        .into_dimensionality::<Ix2>()
+        .expect("matmul requires 2-D tensors")
+}
+
+fn accumulate(grad: &GradCell, contribution: ArrayD<f32>) {
+    let mut slot = grad.borrow_mut();
+    match slot.as_mut() {
+        Some(existing) => *existing += &contribution,
+        None => *slot = Some(contribution),
+    }
+}
+
+/// Sum a gradient down to `shape`, undoing any NumPy-style broadcasting that
+/// happened on the forward pass (e.g. a `[out]` bias broadcast against a
+/// `[batch, out]` activation).
+fn reduce_grad_to_shape(grad: &ArrayD<f32>, shape: &[usize]) -> ArrayD<f32> {
+    let mut g = grad.clone();
+
+    while g.ndim() > shape.len() {
+        g = g.sum_axis(Axis(0));
+    }
+
+    for (axis, (&g_dim, &t_dim)) in g.shape().to_vec().iter().zip(shape.iter()).enumerate() {
+        if t_dim == 1 && g_dim != 1 {
+            g = g.sum_axis(Axis(axis)).insert_axis(Axis(axis));
+        }
+    }
+
+    g.clone().into_shape(IxDyn(shape)).unwrap_or(g)
+}
+
+// Implement basic arithmetic operations
+impl Add for &Tensor {
+    type Output = Tensor;
+
+    fn add(self, other: Self) -> Self::Output {
+        self.add(other)
+    }
+}
+
+impl Add<f32> for &Tensor {
+    type Output = Tensor;
+
+    fn add(self, scalar: f32) -> Self::Output {
+        let result = &*self.data.borrow() + scalar;
+        Tensor::new(result, self.requires_grad)
+    }
+}
+
+impl Mul for &Tensor {
+    type Output = Tensor;
+
+    fn mul(self, other: Self) -> Self::Output {
+        self.mul(other)
+    }
+}
+
+impl Mul<f32> for &Tensor {
+    type Output = Tensor;
+
+    fn mul(self, scalar: f32) -> Self::Output {
+        let result = &*self.data.borrow() * scalar;
+        Tensor::new(result, self.requires_grad)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tensor_creation() {
+        let t = Tensor::zeros((2, 3), false);
+        assert_eq!(t.shape(), &[2, 3]);
+        assert_eq!(t.data().sum(), 0.0);
+    }
+
+    #[test]
+    fn test_tensor_addition() {
+        let a = Tensor::ones((2, 2), false);
+        let b = Tensor::ones((2, 2), false);
+        let c = &a + &b;
+        assert_eq!(c.data().sum(), 8.0); // 2x2 matrix of 2.0s
+    }
+
+    #[test]
+    fn test_tensor_multiplication() {
+        let a = Tensor::ones((2, 2), false);
+        let b = &a * 2.0;
+        assert_eq!(b.data().sum(), 8.0); // 2x2 matrix of 2.0s
+    }
+
+    #[test]
+    fn test_matmul_backward_accumulates() {
+        let a = Tensor::new(Array::from_elem(IxDyn(&[2, 2]), 1.0), true);
+        let w = Tensor::new(Array::from_elem(IxDyn(&[2, 2]), 2.0), true);
+
+        let out = a.matmul(&w);
+        out.backward();
+
+        // grad_a = grad_out . w^T = ones(2,2) . twos(2,2) -> each entry 4, sum 16
+        assert_eq!(a.grad().unwrap().sum(), 16.0);
+        // grad_w = a^T . grad_out = ones(2,2) . ones(2,2) -> each entry 2, sum 8
+        assert_eq!(w.grad().unwrap().sum(), 8.0);
+    }
+
+    #[test]
+    fn test_shared_parameter_grad_accumulates() {
+        let p = Tensor::new(Array::from_elem(IxDyn(&[2]), 1.0), true);
+
+        let out = p.add(&p);
+        out.backward();
+
+        // p feeds into the sum twice, so its gradient should be 2 per element.
+        assert_eq!(p.grad().unwrap(), Array::from_elem(IxDyn(&[2]), 2.0));
+    }
+
+    #[test]
+    fn test_relu_backward_masks_negatives() {
+        use ndarray::array;
+
+        let x = Tensor::new(array![[-1.0, 2.0]].into_dyn(), true);
+        let out = x.relu();
+        out.backward();
+
+        assert_eq!(x.grad().unwrap(), array![[0.0, 1.0]].into_dyn());
+    }
+
+    #[test]
+    fn test_try_matmul_rejects_mismatched_inner_dims() {
+        let a = Tensor::zeros((2, 3), false);
+        let b = Tensor::zeros((4, 5), false);
+
+        assert_eq!(
+            a.try_matmul(&b).unwrap_err(),
+            TensorError::MatmulShapeMismatch {
+                lhs: vec![2, 3],
+                rhs: vec![4, 5],
+            }
+        );
+    }
+
+    #[test]
+    fn test_try_add_allows_broadcast_but_rejects_incompatible_shapes() {
+        let batch = Tensor::zeros((4, 3), false);
+        let bias = Tensor::zeros(3, false);
+        assert!(batch.try_add(&bias).is_ok());
+
+        let mismatched = Tensor::zeros(5, false);
+        assert_eq!(
+            batch.try_add(&mismatched).unwrap_err(),
+            TensorError::IncompatibleShapes {
+                lhs: vec![4, 3],
+                rhs: vec![5],
+            }
+        );
+    }
+
+    #[test]
+    fn test_clones_share_live_data_storage() {
+        // Module::parameters() hands out clones of its tensors; an optimizer
+        // mutating a clone's data must be visible through the original, the
+        // same way mutating its grad already is.
+        let original = Tensor::new(Array::from_elem(IxDyn(&[2]), 1.0), true);
+        let handle = original.clone();
+
+        *handle.data_mut() = Array::from_elem(IxDyn(&[2]), 5.0);
+
+        assert_eq!(original.data(), Array::from_elem(IxDyn(&[2]), 5.0));
+    }
+}
+
+#[cfg(feature = "serialize")]
+mod serde_support {
+    use super::Tensor;
+    use ndarray::{Array, IxDyn};
+    use serde::{de::Error as _, Deserialize, Deserializer, Serialize, Serializer};
+
+    /// On-disk representation of a [`Tensor`]: just its shape and flat data,
+    /// since the gradient graph is never serialized.
+    #[derive(Serialize, Deserialize)]
+    struct TensorData {
+        shape: Vec<usize>,
+        data: Vec<f32>,
+    }
+
+    impl Serialize for Tensor {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            TensorData {
+                shape: self.shape().to_vec(),
+                data: self.data().iter().copied().collect(),
+            }
+            .serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Tensor {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let raw = TensorData::deserialize(deserializer)?;
+            let data =
+                Array::from_shape_vec(IxDyn(&raw.shape), raw.data).map_err(D::Error::custom)?;
+            Ok(Tensor::new(data, false))
+        }
+    }
+}